@@ -0,0 +1,70 @@
+//! Shared Bresenham line rasterization, used by any primitive that needs to connect two points
+//! with a 1px-wide line (`Polyline`, `Polygon`, and flattened `Path` segments).
+
+use crate::coord::Coord;
+
+/// Iterates the integer points of a line from `p0` to `p1` (inclusive of both endpoints) using
+/// Bresenham's algorithm.
+#[derive(Debug, Clone)]
+pub(crate) struct Bresenham {
+    x: i32,
+    y: i32,
+    x1: i32,
+    y1: i32,
+    dx: i32,
+    dy: i32,
+    sx: i32,
+    sy: i32,
+    err: i32,
+    done: bool,
+}
+
+impl Bresenham {
+    pub(crate) fn new(p0: Coord, p1: Coord) -> Self {
+        let dx = (p1.0 - p0.0).abs();
+        let dy = -(p1.1 - p0.1).abs();
+        let sx = if p0.0 < p1.0 { 1 } else { -1 };
+        let sy = if p0.1 < p1.1 { 1 } else { -1 };
+
+        Bresenham {
+            x: p0.0,
+            y: p0.1,
+            x1: p1.0,
+            y1: p1.1,
+            dx,
+            dy,
+            sx,
+            sy,
+            err: dx + dy,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let point = Coord::new(self.x, self.y);
+
+        if self.x == self.x1 && self.y == self.y1 {
+            self.done = true;
+        } else {
+            let e2 = 2 * self.err;
+            if e2 >= self.dy {
+                self.err += self.dy;
+                self.x += self.sx;
+            }
+            if e2 <= self.dx {
+                self.err += self.dx;
+                self.y += self.sy;
+            }
+        }
+
+        Some(point)
+    }
+}
@@ -0,0 +1,118 @@
+//! The circle primitive
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::ellipse::{Ellipse, EllipseIterator};
+use crate::primitives::Primitive;
+use crate::style::{Style, StrokeAlignment, WithStyle};
+
+/// A circle, described by its center point and radius.
+///
+/// Implemented as a thin wrapper over [`Ellipse`](../ellipse/struct.Ellipse.html) with equal `rx`
+/// and `ry`, so it gets the same concentric-ring stroke-width/alignment rendering for free.
+///
+/// ```rust
+/// use embedded_graphics::primitives::Circle;
+/// use embedded_graphics::coord::Coord;
+///
+/// let circle: Circle<u8> = Circle::new(Coord::new(10, 20), 30);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Circle<C: PixelColor> {
+    inner: Ellipse<C>,
+}
+
+impl<C> Circle<C>
+where
+    C: PixelColor,
+{
+    /// Create a new circle centered at `center` with the given `radius`
+    pub fn new(center: Coord, radius: u32) -> Self {
+        Circle {
+            inner: Ellipse::new(center, radius, radius),
+        }
+    }
+}
+
+impl<C> Primitive for Circle<C> where C: PixelColor {}
+
+impl<C> Dimensions for Circle<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.inner.top_left()
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.inner.bottom_right()
+    }
+
+    fn size(&self) -> Coord {
+        self.inner.size()
+    }
+}
+
+impl<C> WithStyle<C> for Circle<C>
+where
+    C: PixelColor,
+{
+    fn style(self, style: Style<C>) -> Self {
+        Circle {
+            inner: self.inner.style(style),
+        }
+    }
+
+    fn stroke(self, color: Option<C>) -> Self {
+        Circle {
+            inner: self.inner.stroke(color),
+        }
+    }
+
+    fn stroke_width(self, width: u8) -> Self {
+        Circle {
+            inner: self.inner.stroke_width(width),
+        }
+    }
+
+    fn stroke_alignment(self, alignment: StrokeAlignment) -> Self {
+        Circle {
+            inner: self.inner.stroke_alignment(alignment),
+        }
+    }
+
+    fn fill(self, color: Option<C>) -> Self {
+        Circle {
+            inner: self.inner.fill(color),
+        }
+    }
+}
+
+/// Pixel iterator for the `Circle` primitive; delegates to
+/// [`EllipseIterator`](../ellipse/struct.EllipseIterator.html).
+#[derive(Debug, Clone)]
+pub struct CircleIterator<C: PixelColor>(EllipseIterator<C>);
+
+impl<C> Iterator for CircleIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<C> IntoIterator for Circle<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = CircleIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CircleIterator(self.inner.into_iter())
+    }
+}
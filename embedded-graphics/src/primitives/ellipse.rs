@@ -0,0 +1,464 @@
+//! The ellipse primitive
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::Primitive;
+use crate::style::{stroke_offsets, Style, StrokeAlignment, WithStyle};
+
+/// An ellipse, described by its center point and independent x/y radii.
+///
+/// Unlike [`Circle`](../circle/struct.Circle.html), which is constrained to a 1:1 aspect ratio,
+/// `Ellipse` takes separate `rx` and `ry` radii so it can be stretched along either axis.
+///
+/// ```rust
+/// use embedded_graphics::primitives::Ellipse;
+/// use embedded_graphics::coord::Coord;
+///
+/// let ellipse: Ellipse<u8> = Ellipse::new(Coord::new(10, 20), 30, 15);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Ellipse<C: PixelColor> {
+    /// Center point
+    pub center: Coord,
+    /// Horizontal (x) radius
+    pub rx: u32,
+    /// Vertical (y) radius
+    pub ry: u32,
+    /// Object style
+    pub style: Style<C>,
+}
+
+impl<C> Ellipse<C>
+where
+    C: PixelColor,
+{
+    /// Create a new ellipse centered at `center` with semi-axes `rx` and `ry`
+    pub fn new(center: Coord, rx: u32, ry: u32) -> Self {
+        Ellipse {
+            center,
+            rx,
+            ry,
+            style: Style::default(),
+        }
+    }
+}
+
+impl<C> Primitive for Ellipse<C> where C: PixelColor {}
+
+impl<C> Dimensions for Ellipse<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        Coord::new(
+            self.center.0 - self.rx as i32,
+            self.center.1 - self.ry as i32,
+        )
+    }
+
+    fn bottom_right(&self) -> Coord {
+        Coord::new(
+            self.center.0 + self.rx as i32,
+            self.center.1 + self.ry as i32,
+        )
+    }
+
+    fn size(&self) -> Coord {
+        self.bottom_right() - self.top_left()
+    }
+}
+
+impl<C> WithStyle<C> for Ellipse<C>
+where
+    C: PixelColor,
+{
+    fn style(self, style: Style<C>) -> Self {
+        Ellipse { style, ..self }
+    }
+
+    fn stroke(self, color: Option<C>) -> Self {
+        Ellipse {
+            style: Style {
+                stroke_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn stroke_width(self, width: u8) -> Self {
+        Ellipse {
+            style: Style {
+                stroke_width: width,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn stroke_alignment(self, alignment: StrokeAlignment) -> Self {
+        Ellipse {
+            style: Style {
+                stroke_alignment: alignment,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn fill(self, color: Option<C>) -> Self {
+        Ellipse {
+            style: Style {
+                fill_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+}
+
+/// The two regions of the integer midpoint-ellipse algorithm, split where the tangent slope
+/// crosses -1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Region {
+    Initial,
+    One,
+    Two,
+    Done,
+}
+
+/// Walks one quadrant (`x >= 0`, `y >= 0`) of an ellipse boundary using the integer
+/// midpoint-ellipse algorithm. `pub(crate)` so `Rect` can reuse it (as a circle, `a == b`) to
+/// rasterize rounded corners.
+#[derive(Debug, Clone)]
+pub(crate) struct MidpointEllipse {
+    a2: i64,
+    b2: i64,
+    x: i64,
+    y: i64,
+    dx: i64,
+    dy: i64,
+    d1: i64,
+    d2: i64,
+    region: Region,
+}
+
+impl MidpointEllipse {
+    pub(crate) fn new(a: i64, b: i64) -> Self {
+        let a2 = a * a;
+        let b2 = b * b;
+
+        MidpointEllipse {
+            a2,
+            b2,
+            x: 0,
+            y: b,
+            dx: 0,
+            dy: 2 * a2 * b,
+            d1: b2 - a2 * b + a2 / 4,
+            d2: 0,
+            region: Region::Initial,
+        }
+    }
+
+    pub(crate) fn next(&mut self) -> Option<(i64, i64)> {
+        match self.region {
+            Region::Initial => {
+                self.region = Region::One;
+                Some((self.x, self.y))
+            }
+            Region::One => {
+                if self.dx < self.dy {
+                    self.x += 1;
+                    self.dx += 2 * self.b2;
+                    if self.d1 < 0 {
+                        self.d1 += self.dx + self.b2;
+                    } else {
+                        self.y -= 1;
+                        self.dy -= 2 * self.a2;
+                        self.d1 += self.dx - self.dy + self.b2;
+                    }
+                    Some((self.x, self.y))
+                } else {
+                    self.d2 = self.b2 * (2 * self.x + 1) * (2 * self.x + 1) / 4
+                        + self.a2 * (self.y - 1) * (self.y - 1)
+                        - self.a2 * self.b2;
+                    self.region = Region::Two;
+                    self.next()
+                }
+            }
+            Region::Two => {
+                if self.y > 0 {
+                    self.y -= 1;
+                    self.dy -= 2 * self.a2;
+                    if self.d2 > 0 {
+                        self.d2 += self.a2 - self.dy;
+                    } else {
+                        self.x += 1;
+                        self.dx += 2 * self.b2;
+                        self.d2 += self.dx - self.dy + self.a2;
+                    }
+                    Some((self.x, self.y))
+                } else {
+                    self.region = Region::Done;
+                    None
+                }
+            }
+            Region::Done => None,
+        }
+    }
+}
+
+/// A run of pixels, of a single fixed `color`, along a single row (or, for the degenerate
+/// `rx == 0` case, a single column). `pub(crate)` so `Rect` can reuse it for its own rows: a span
+/// with `filled: true` walks every point (a filled row, or a flat top/bottom edge), while
+/// `filled: false` jumps straight from `from` to `to` (just the two boundary points of an
+/// unfilled row).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Span<C: PixelColor> {
+    /// The row (or column, if `vertical`) this span lies on
+    pub(crate) primary: i32,
+    pub(crate) from: i32,
+    pub(crate) to: i32,
+    pub(crate) cur: i32,
+    pub(crate) filled: bool,
+    pub(crate) vertical: bool,
+    pub(crate) color: C,
+}
+
+impl<C: PixelColor> Span<C> {
+    /// Returns the next `(x, y)` point in the span, or `None` once exhausted.
+    pub(crate) fn next_point(&mut self) -> Option<(i32, i32)> {
+        if self.cur > self.to {
+            return None;
+        }
+
+        let point = if self.vertical {
+            (self.primary, self.cur)
+        } else {
+            (self.cur, self.primary)
+        };
+
+        self.cur += if self.filled || self.from == self.to {
+            1
+        } else {
+            self.to - self.from
+        };
+
+        Some(point)
+    }
+}
+
+/// One ring of the ellipse's outline (a single radius), driving either the midpoint algorithm or,
+/// for a degenerate `rx == 0` or `ry == 0` radius, a single straight span.
+#[derive(Debug, Clone)]
+enum Ring<C: PixelColor> {
+    Mid {
+        mid: MidpointEllipse,
+        filled: bool,
+        color: C,
+    },
+    Degenerate(Option<Span<C>>),
+}
+
+impl<C: PixelColor> Ring<C> {
+    /// `rx` and `ry` are clamped to `0` by the caller, so only non-negative radii reach here.
+    ///
+    /// A degenerate ring (`rx <= 0` or `ry <= 0`) *is* the whole line, not a single step of a
+    /// curved ring, so it always walks every point from `from` to `to` — unlike a `Mid` ring,
+    /// where an unfilled span intentionally jumps straight to its two endpoints.
+    fn new(center: Coord, rx: i64, ry: i64, filled: bool, color: C) -> Self {
+        if rx <= 0 || ry <= 0 {
+            let span = if rx <= 0 && ry <= 0 {
+                None
+            } else if rx <= 0 {
+                Some(Span {
+                    primary: center.0,
+                    from: center.1 - ry as i32,
+                    to: center.1 + ry as i32,
+                    cur: center.1 - ry as i32,
+                    filled: true,
+                    vertical: true,
+                    color,
+                })
+            } else {
+                Some(Span {
+                    primary: center.1,
+                    from: center.0 - rx as i32,
+                    to: center.0 + rx as i32,
+                    cur: center.0 - rx as i32,
+                    filled: true,
+                    vertical: false,
+                    color,
+                })
+            };
+            Ring::Degenerate(span)
+        } else {
+            Ring::Mid {
+                mid: MidpointEllipse::new(rx, ry),
+                filled,
+                color,
+            }
+        }
+    }
+}
+
+/// Pixel iterator for the `Ellipse` primitive.
+///
+/// Renders as a (clamped) sequence of rings: first the solid fill, inset to the inner edge of the
+/// stroke band, then one ring per pixel offset in the stroke band (`stroke_width` wide, shifted by
+/// `stroke_alignment`), so the stroke is effectively drawn as several concentric outlines.
+#[derive(Debug, Clone)]
+pub struct EllipseIterator<C: PixelColor> {
+    center: Coord,
+    rx: i64,
+    ry: i64,
+    fill_color: Option<C>,
+    fill_offset: i64,
+    fill_pending: bool,
+    stroke_color: Option<C>,
+    stroke_k: i64,
+    stroke_to: i64,
+    current: Option<Ring<C>>,
+    spans: [Option<Span<C>>; 2],
+    span_idx: usize,
+}
+
+impl<C> Iterator for EllipseIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(span) = self.spans[self.span_idx].as_mut() {
+                if let Some((x, y)) = span.next_point() {
+                    return Some(Pixel(Coord::new(x, y), span.color));
+                } else {
+                    self.spans[self.span_idx] = None;
+                }
+            }
+
+            if self.span_idx == 0 && self.spans[1].is_some() {
+                self.span_idx = 1;
+                continue;
+            }
+
+            if let Some(ring) = self.current.as_mut() {
+                match ring {
+                    Ring::Mid { mid, filled, color } => {
+                        if let Some((qx, qy)) = mid.next() {
+                            let (filled, color) = (*filled, *color);
+                            let top = self.center.1 - qy as i32;
+                            let bottom = self.center.1 + qy as i32;
+                            let from = self.center.0 - qx as i32;
+                            let to = self.center.0 + qx as i32;
+
+                            self.spans[0] = Some(Span {
+                                primary: top,
+                                from,
+                                to,
+                                cur: from,
+                                filled,
+                                vertical: false,
+                                color,
+                            });
+                            self.spans[1] = if bottom != top {
+                                Some(Span {
+                                    primary: bottom,
+                                    from,
+                                    to,
+                                    cur: from,
+                                    filled,
+                                    vertical: false,
+                                    color,
+                                })
+                            } else {
+                                None
+                            };
+                            self.span_idx = 0;
+                            continue;
+                        } else {
+                            self.current = None;
+                        }
+                    }
+                    Ring::Degenerate(span) => {
+                        if let Some(s) = span.take() {
+                            self.spans[0] = Some(s);
+                            self.spans[1] = None;
+                            self.span_idx = 0;
+                            self.current = None;
+                            continue;
+                        } else {
+                            self.current = None;
+                        }
+                    }
+                }
+            }
+
+            if self.fill_pending {
+                self.fill_pending = false;
+                if let Some(color) = self.fill_color {
+                    let rx = (self.rx + self.fill_offset).max(0);
+                    let ry = (self.ry + self.fill_offset).max(0);
+                    self.current = Some(Ring::new(self.center, rx, ry, true, color));
+                    continue;
+                }
+            }
+
+            if let Some(color) = self.stroke_color {
+                if self.stroke_k <= self.stroke_to {
+                    let k = self.stroke_k;
+                    self.stroke_k += 1;
+                    let rx = (self.rx + k).max(0);
+                    let ry = (self.ry + k).max(0);
+                    self.current = Some(Ring::new(self.center, rx, ry, false, color));
+                    continue;
+                }
+            }
+
+            return None;
+        }
+    }
+}
+
+impl<C> IntoIterator for Ellipse<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = EllipseIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let rx = self.rx as i64;
+        let ry = self.ry as i64;
+
+        let (from, to) = if self.style.stroke_color.is_some() {
+            stroke_offsets(self.style.stroke_width, self.style.stroke_alignment)
+        } else {
+            (0, 0)
+        };
+
+        // Past `-rx.max(ry)`, both radii are already clamped to 0 by `EllipseIterator::next`, so
+        // every further step would re-emit the same empty ring. Clamp the start of the stroke
+        // band there so an oversized `stroke_width` can't loop through a long run of no-ops.
+        let stroke_k = (from as i64).max(-(rx.max(ry)));
+
+        EllipseIterator {
+            center: self.center,
+            rx,
+            ry,
+            fill_color: self.style.fill_color,
+            fill_offset: from as i64,
+            fill_pending: true,
+            stroke_color: self.style.stroke_color,
+            stroke_k,
+            stroke_to: to as i64,
+            current: None,
+            spans: [None, None],
+            span_idx: 0,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! The line primitive
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::bresenham::Bresenham;
+use crate::primitives::Primitive;
+use crate::style::{Style, StrokeAlignment, WithStyle};
+
+/// A straight line between two points.
+///
+/// Note that, like [`Polyline`](../polyline/struct.Polyline.html), only the `stroke` property has
+/// any effect; a line is never filled.
+///
+/// ```rust
+/// use embedded_graphics::primitives::Line;
+/// use embedded_graphics::coord::Coord;
+///
+/// let line: Line<u8> = Line::new(Coord::new(10, 20), Coord::new(30, 40));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Line<C: PixelColor> {
+    /// Start point
+    pub start: Coord,
+    /// End point
+    pub end: Coord,
+    /// Object style
+    pub style: Style<C>,
+}
+
+impl<C> Line<C>
+where
+    C: PixelColor,
+{
+    /// Create a new line from `start` to `end`
+    pub fn new(start: Coord, end: Coord) -> Self {
+        Line {
+            start,
+            end,
+            style: Style::default(),
+        }
+    }
+}
+
+impl<C> Primitive for Line<C> where C: PixelColor {}
+
+impl<C> Dimensions for Line<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        Coord::new(self.start.0.min(self.end.0), self.start.1.min(self.end.1))
+    }
+
+    fn bottom_right(&self) -> Coord {
+        Coord::new(self.start.0.max(self.end.0), self.start.1.max(self.end.1))
+    }
+
+    fn size(&self) -> Coord {
+        self.bottom_right() - self.top_left()
+    }
+}
+
+impl<C> WithStyle<C> for Line<C>
+where
+    C: PixelColor,
+{
+    fn style(self, style: Style<C>) -> Self {
+        Line { style, ..self }
+    }
+
+    fn stroke(self, color: Option<C>) -> Self {
+        Line {
+            style: Style {
+                stroke_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    // Accepted for consistency with the other primitives; a line is still rasterized as a single
+    // 1px Bresenham run, so width/alignment have no visual effect yet. See `Ellipse`'s
+    // concentric-ring stroke for the primitive that currently honors them.
+    fn stroke_width(self, width: u8) -> Self {
+        Line {
+            style: Style {
+                stroke_width: width,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn stroke_alignment(self, alignment: StrokeAlignment) -> Self {
+        Line {
+            style: Style {
+                stroke_alignment: alignment,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn fill(self, color: Option<C>) -> Self {
+        Line {
+            style: Style {
+                fill_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+}
+
+/// Pixel iterator for the `Line` primitive.
+#[derive(Debug, Clone)]
+pub struct LineIterator<C: PixelColor> {
+    style: Style<C>,
+    bresenham: Bresenham,
+}
+
+impl<C> Iterator for LineIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let point = self.bresenham.next()?;
+
+            if let Some(color) = self.style.stroke_color {
+                return Some(Pixel(point, color));
+            }
+        }
+    }
+}
+
+impl<C> IntoIterator for Line<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = LineIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LineIterator {
+            style: self.style,
+            bresenham: Bresenham::new(self.start, self.end),
+        }
+    }
+}
@@ -1,19 +1,180 @@
 //! Graphics primitives
 
-use crate::drawable::Dimensions;
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
 
+mod bresenham;
 pub mod circle;
+pub mod ellipse;
 pub mod line;
+pub mod path;
+pub mod polygon;
+pub mod polyline;
 pub mod rect;
 pub mod triangle;
 
 /// Primitive trait
 pub trait Primitive: Dimensions {}
 
-pub use self::circle::Circle;
-pub use self::line::Line;
-pub use self::rect::Rect;
-pub use self::triangle::Triangle;
+pub use self::circle::{Circle, CircleIterator};
+pub use self::ellipse::{Ellipse, EllipseIterator};
+pub use self::line::{Line, LineIterator};
+pub use self::path::{Path, PathIterator};
+pub use self::polygon::{Polygon, PolygonIterator};
+pub use self::polyline::{Polyline, PolylineIterator};
+pub use self::rect::{Rect, RectIterator};
+pub use self::triangle::{Triangle, TriangleIterator};
+
+/// A heterogeneous collection of primitives, wrapped in a single enum.
+///
+/// Because `Circle`, `Line`, `Rect` and `Triangle` are all distinct generic types, they can't be
+/// stored together in a single `Vec` without boxing each one behind a trait object. `Primitives`
+/// instead holds one concrete primitive per variant, so a mixed list of shapes can be collected
+/// as `Vec<Primitives<C>>` and drawn with a single `.into_iter()`, with each variant's drawing
+/// code still monomorphized rather than dispatched through `Box<dyn Trait>`.
+///
+/// ```rust
+/// use embedded_graphics::primitives::{Circle, Primitives};
+/// use embedded_graphics::coord::Coord;
+///
+/// let shapes: Vec<Primitives<u8>> = vec![
+///     Circle::new(Coord::new(10, 20), 30).into(),
+/// ];
+///
+/// for shape in shapes {
+///     for _pixel in shape {
+///         // draw pixel
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum Primitives<C: PixelColor> {
+    /// A circle
+    Circle(Circle<C>),
+    /// A line
+    Line(Line<C>),
+    /// A rectangle
+    Rect(Rect<C>),
+    /// A triangle
+    Triangle(Triangle<C>),
+}
+
+impl<C> Dimensions for Primitives<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        match self {
+            Primitives::Circle(shape) => shape.top_left(),
+            Primitives::Line(shape) => shape.top_left(),
+            Primitives::Rect(shape) => shape.top_left(),
+            Primitives::Triangle(shape) => shape.top_left(),
+        }
+    }
+
+    fn bottom_right(&self) -> Coord {
+        match self {
+            Primitives::Circle(shape) => shape.bottom_right(),
+            Primitives::Line(shape) => shape.bottom_right(),
+            Primitives::Rect(shape) => shape.bottom_right(),
+            Primitives::Triangle(shape) => shape.bottom_right(),
+        }
+    }
+
+    fn size(&self) -> Coord {
+        match self {
+            Primitives::Circle(shape) => shape.size(),
+            Primitives::Line(shape) => shape.size(),
+            Primitives::Rect(shape) => shape.size(),
+            Primitives::Triangle(shape) => shape.size(),
+        }
+    }
+}
+
+impl<C> Primitive for Primitives<C> where C: PixelColor {}
+
+impl<C> From<Circle<C>> for Primitives<C>
+where
+    C: PixelColor,
+{
+    fn from(shape: Circle<C>) -> Self {
+        Primitives::Circle(shape)
+    }
+}
+
+impl<C> From<Line<C>> for Primitives<C>
+where
+    C: PixelColor,
+{
+    fn from(shape: Line<C>) -> Self {
+        Primitives::Line(shape)
+    }
+}
+
+impl<C> From<Rect<C>> for Primitives<C>
+where
+    C: PixelColor,
+{
+    fn from(shape: Rect<C>) -> Self {
+        Primitives::Rect(shape)
+    }
+}
+
+impl<C> From<Triangle<C>> for Primitives<C>
+where
+    C: PixelColor,
+{
+    fn from(shape: Triangle<C>) -> Self {
+        Primitives::Triangle(shape)
+    }
+}
+
+/// Pixel iterator for the `Primitives` enum, delegating to the wrapped concrete type's iterator.
+#[derive(Debug, Clone)]
+pub enum PrimitivesIterator<C: PixelColor> {
+    /// Iterator over a [`Circle`](struct.Circle.html)
+    Circle(CircleIterator<C>),
+    /// Iterator over a [`Line`](struct.Line.html)
+    Line(LineIterator<C>),
+    /// Iterator over a [`Rect`](struct.Rect.html)
+    Rect(RectIterator<C>),
+    /// Iterator over a [`Triangle`](struct.Triangle.html)
+    Triangle(TriangleIterator<C>),
+}
+
+impl<C> Iterator for PrimitivesIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            PrimitivesIterator::Circle(it) => it.next(),
+            PrimitivesIterator::Line(it) => it.next(),
+            PrimitivesIterator::Rect(it) => it.next(),
+            PrimitivesIterator::Triangle(it) => it.next(),
+        }
+    }
+}
+
+impl<C> IntoIterator for Primitives<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PrimitivesIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Primitives::Circle(shape) => PrimitivesIterator::Circle(shape.into_iter()),
+            Primitives::Line(shape) => PrimitivesIterator::Line(shape.into_iter()),
+            Primitives::Rect(shape) => PrimitivesIterator::Rect(shape.into_iter()),
+            Primitives::Triangle(shape) => PrimitivesIterator::Triangle(shape.into_iter()),
+        }
+    }
+}
 
 /// Create a [`Circle`](./primitives/circle/struct.Circle.html) with optional styling using a
 /// convenient macro.
@@ -37,8 +198,21 @@ pub use self::triangle::Triangle;
 /// let circle: Circle<u8> = circle!((10, 20), 30, stroke = Some(5u8), fill = Some(10u8));
 /// let circle: Circle<u8> = Circle::new(Coord::new(10, 20), 30).stroke(Some(5u8)).fill(Some(10u8));
 /// ```
+///
+/// Prefix the arguments with `@primitive` to wrap the result in a
+/// [`Primitives`](./primitives/enum.Primitives.html) enum, so it can be stored alongside other
+/// shapes:
+///
+/// ```rust
+/// use embedded_graphics::{circle, primitives::Primitives};
+///
+/// let circle: Primitives<u8> = circle!(@primitive (10, 20), 30);
+/// ```
 #[macro_export]
 macro_rules! circle {
+    (@primitive $($tt:tt)*) => {
+        $crate::primitives::Primitives::from($crate::circle!($($tt)*))
+    };
     (($cx:expr, $cy:expr), $r:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
         #[allow(unused_imports)]
         use $crate::style::WithStyle;
@@ -47,6 +221,54 @@ macro_rules! circle {
     }};
 }
 
+/// Create an [`Ellipse`](./primitives/ellipse/struct.Ellipse.html) with optional styling using a
+/// convenient macro.
+///
+/// ```rust
+/// use embedded_graphics::{ellipse, style::Style, primitives::Ellipse};
+///
+/// let empty_ellipse: Ellipse<u8> = ellipse!((10, 20), 30, 15);
+/// let filled_ellipse: Ellipse<u8> = ellipse!((10, 20), 30, 15, stroke = Some(5u8), fill = Some(10u8));
+/// let ellipse_default_style: Ellipse<u8> = ellipse!((10, 20), 30, 15, style = Style::default());
+/// ```
+///
+/// `stroke_width` and `stroke_alignment` work the same way, offsetting the stroked outline
+/// inward, outward, or straddling it:
+///
+/// ```rust
+/// use embedded_graphics::{ellipse, style::StrokeAlignment, primitives::Ellipse};
+///
+/// let thick: Ellipse<u8> = ellipse!(
+///     (10, 20), 30, 15,
+///     stroke = Some(5u8),
+///     stroke_width = 3,
+///     stroke_alignment = StrokeAlignment::Inside,
+/// );
+/// ```
+///
+/// Style properties like `stroke` map to the method calls on the
+/// [`WithStyle`](style/trait.WithStyle.html) trait. For example, the following code makes two
+/// identical ellipses:
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::{ellipse, style::Style, primitives::Ellipse};
+///
+/// let ellipse: Ellipse<u8> = ellipse!((10, 20), 30, 15, stroke = Some(5u8), fill = Some(10u8));
+/// let ellipse: Ellipse<u8> = Ellipse::new(Coord::new(10, 20), 30, 15)
+///     .stroke(Some(5u8))
+///     .fill(Some(10u8));
+/// ```
+#[macro_export]
+macro_rules! ellipse {
+    (($cx:expr, $cy:expr), $rx:expr, $ry:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::style::WithStyle;
+        $crate::primitives::Ellipse::new($crate::coord::Coord::new($cx, $cy), $rx, $ry)
+            $( .$style_key($style_value) )*
+    }};
+}
+
 /// Create a [`Line`](./primitives/line/struct.Line.html) with optional styling using a
 /// convenient macro.
 ///
@@ -72,8 +294,15 @@ macro_rules! circle {
 ///     .stroke(Some(5u8))
 ///     .fill(Some(10u8));
 /// ```
+///
+/// Prefix the arguments with `@primitive` to wrap the result in a
+/// [`Primitives`](./primitives/enum.Primitives.html) enum, so it can be stored alongside other
+/// shapes.
 #[macro_export]
 macro_rules! line {
+    (@primitive $($tt:tt)*) => {
+        $crate::primitives::Primitives::from($crate::line!($($tt)*))
+    };
     (($x1:expr, $y1:expr), ($x2:expr, $y2:expr) $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
         #[allow(unused_imports)]
         use $crate::style::WithStyle;
@@ -93,6 +322,22 @@ macro_rules! line {
 /// let rect_default_style: Rect<u8> = rect!((10, 20), (30, 40), style = Style::default());
 /// ```
 ///
+/// `stroke_width` and `stroke_alignment` work the same way as on [`ellipse!`](macro.ellipse.html).
+/// `corner_radius` is `Rect`-specific and rounds the corners, rasterized as arcs of the
+/// midpoint-ellipse routine:
+///
+/// ```rust
+/// use embedded_graphics::{rect, style::StrokeAlignment, primitives::Rect};
+///
+/// let rounded: Rect<u8> = rect!(
+///     (0, 0), (10, 10),
+///     stroke = Some(1u8),
+///     stroke_width = 3,
+///     stroke_alignment = StrokeAlignment::Inside,
+///     corner_radius = 2,
+/// );
+/// ```
+///
 /// Style properties like `stroke` map to the method calls on the
 /// [`WithStyle`](style/trait.WithStyle.html) trait. For example, the following code makes two
 /// identical rectangles:
@@ -106,8 +351,15 @@ macro_rules! line {
 ///     .stroke(Some(5u8))
 ///     .fill(Some(10u8));
 /// ```
+///
+/// Prefix the arguments with `@primitive` to wrap the result in a
+/// [`Primitives`](./primitives/enum.Primitives.html) enum, so it can be stored alongside other
+/// shapes.
 #[macro_export]
 macro_rules! rect {
+    (@primitive $($tt:tt)*) => {
+        $crate::primitives::Primitives::from($crate::rect!($($tt)*))
+    };
     (($x1:expr, $y1:expr), ($x2:expr, $y2:expr) $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
         #[allow(unused_imports)]
         use $crate::style::WithStyle;
@@ -140,8 +392,15 @@ macro_rules! rect {
 ///     .stroke(Some(5u8))
 ///     .fill(Some(10u8));
 /// ```
+///
+/// Prefix the arguments with `@primitive` to wrap the result in a
+/// [`Primitives`](./primitives/enum.Primitives.html) enum, so it can be stored alongside other
+/// shapes.
 #[macro_export]
 macro_rules! triangle {
+    (@primitive $($tt:tt)*) => {
+        $crate::primitives::Primitives::from($crate::triangle!($($tt)*))
+    };
     (($x1:expr, $y1:expr), ($x2:expr, $y2:expr), ($x3:expr, $y3:expr) $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
         #[allow(unused_imports)]
         use $crate::style::WithStyle;
@@ -150,10 +409,75 @@ macro_rules! triangle {
     }};
 }
 
+/// Create a [`Polyline`](./primitives/polyline/struct.Polyline.html) with optional styling using
+/// a convenient macro.
+///
+/// Note that, like [`line!`](macro.line.html), only the `stroke` property has any effect.
+///
+/// ```rust
+/// use embedded_graphics::{polyline, style::Style, primitives::Polyline};
+///
+/// let polyline: Polyline<u8, 3> = polyline!((10, 20), (30, 40), (50, 10));
+/// let stroke_polyline: Polyline<u8, 3> = polyline!((10, 20), (30, 40), (50, 10), stroke = Some(5u8));
+/// ```
+#[macro_export]
+macro_rules! polyline {
+    ( $( ($x:expr, $y:expr) ),+ $(, $style_key:ident = $style_value:expr )* $(,)? ) => {{
+        #[allow(unused_imports)]
+        use $crate::style::WithStyle;
+        $crate::primitives::Polyline::new([
+            $( $crate::coord::Coord::new($x, $y) ),+
+        ])
+            $( .$style_key($style_value) )*
+    }};
+}
+
+/// Create a [`Polygon`](./primitives/polygon/struct.Polygon.html) with optional styling using a
+/// convenient macro. The last vertex is automatically joined back to the first.
+///
+/// ```rust
+/// use embedded_graphics::{polygon, style::Style, primitives::Polygon};
+///
+/// let empty_polygon: Polygon<u8, 3> = polygon!((10, 20), (30, 40), (50, 10));
+/// let filled_polygon: Polygon<u8, 3> = polygon!((10, 20), (30, 40), (50, 10), stroke = Some(5u8), fill = Some(10u8));
+/// let polygon_default_style: Polygon<u8, 3> = polygon!((10, 20), (30, 40), (50, 10), style = Style::default());
+/// ```
+#[macro_export]
+macro_rules! polygon {
+    ( $( ($x:expr, $y:expr) ),+ $(, $style_key:ident = $style_value:expr )* $(,)? ) => {{
+        #[allow(unused_imports)]
+        use $crate::style::WithStyle;
+        $crate::primitives::Polygon::new([
+            $( $crate::coord::Coord::new($x, $y) ),+
+        ])
+            $( .$style_key($style_value) )*
+    }};
+}
+
+/// Create a [`Path`](./primitives/path/struct.Path.html) by parsing SVG-like path data, with
+/// optional styling using a convenient macro.
+///
+/// ```rust
+/// use embedded_graphics::{path, style::Style, primitives::Path};
+///
+/// let path: Path<u8> = path!("M10,10 L20,20 Z");
+/// let stroked: Path<u8> = path!("M10,10 L20,20 Z", stroke = Some(1u8));
+/// let styled: Path<u8> = path!("M10,10 L20,20 Z", style = Style::default());
+/// ```
+#[macro_export]
+macro_rules! path {
+    ($d:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::style::WithStyle;
+        $crate::primitives::Path::parse($d)
+            $( .$style_key($style_value) )*
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::style::Style;
+    use crate::style::{Style, StrokeAlignment};
 
     #[test]
     fn circle() {
@@ -162,6 +486,108 @@ mod tests {
         let _c: Circle<u8> = circle!((10, 20), 30, style = Style::default());
     }
 
+    #[test]
+    fn ellipse() {
+        let _e: Ellipse<u8> = ellipse!((10, 20), 30, 15);
+        let _e: Ellipse<u8> = ellipse!((10, 20), 30, 15, stroke = Some(1u8), fill = Some(10u8));
+        let _e: Ellipse<u8> = ellipse!((10, 20), 30, 15, style = Style::default());
+    }
+
+    #[test]
+    fn ellipse_outline_iterates() {
+        let e: Ellipse<u8> = ellipse!((0, 0), 10, 5, stroke = Some(1u8));
+
+        assert!(e.into_iter().count() > 0);
+    }
+
+    #[test]
+    fn ellipse_stroke_width_and_alignment() {
+        let _e: Ellipse<u8> = ellipse!(
+            (0, 0), 10, 10,
+            stroke = Some(1u8),
+            stroke_width = 3,
+            stroke_alignment = StrokeAlignment::Inside,
+        );
+    }
+
+    #[test]
+    fn ellipse_stroke_width_widens_the_outline() {
+        let thin: Ellipse<u8> = ellipse!((0, 0), 10, 10, stroke = Some(1u8));
+        let thick: Ellipse<u8> = ellipse!(
+            (0, 0), 10, 10,
+            stroke = Some(1u8),
+            stroke_width = 3,
+            stroke_alignment = StrokeAlignment::Inside,
+        );
+
+        assert!(thick.into_iter().count() > thin.into_iter().count());
+    }
+
+    #[test]
+    fn ellipse_stroke_alignment_moves_the_fill_boundary() {
+        // With an `Outside` stroke, the fill keeps the nominal radius; with `Inside`, the fill
+        // shrinks to make room for the stroke, so it covers fewer pixels.
+        let outside: Ellipse<u8> = ellipse!(
+            (0, 0), 10, 10,
+            stroke = Some(1u8),
+            fill = Some(2u8),
+            stroke_width = 4,
+            stroke_alignment = StrokeAlignment::Outside,
+        );
+        let inside: Ellipse<u8> = ellipse!(
+            (0, 0), 10, 10,
+            stroke = Some(1u8),
+            fill = Some(2u8),
+            stroke_width = 4,
+            stroke_alignment = StrokeAlignment::Inside,
+        );
+
+        assert!(inside.into_iter().count() < outside.into_iter().count());
+    }
+
+    #[test]
+    fn ellipse_degenerate_axes() {
+        let flat: Ellipse<u8> = ellipse!((0, 0), 10, 0, fill = Some(1u8));
+        let narrow: Ellipse<u8> = ellipse!((0, 0), 0, 10, fill = Some(1u8));
+
+        assert_eq!(flat.into_iter().count(), 21);
+        assert_eq!(narrow.into_iter().count(), 21);
+    }
+
+    #[test]
+    fn ellipse_degenerate_axes_stroke_only() {
+        // A degenerate "ring" is the whole line, not a single midpoint-algorithm step, so a
+        // stroke-only (unfilled) degenerate ellipse must still draw every point on the line,
+        // not just its two endpoints.
+        let flat: Ellipse<u8> = ellipse!((0, 0), 10, 0, stroke = Some(1u8));
+        let narrow: Ellipse<u8> = ellipse!((0, 0), 0, 10, stroke = Some(1u8));
+
+        assert_eq!(flat.into_iter().count(), 21);
+        assert_eq!(narrow.into_iter().count(), 21);
+    }
+
+    #[test]
+    fn ellipse_oversized_stroke_width_does_not_loop_past_the_radii() {
+        // `stroke_width` far larger than the radii used to walk a `stroke_k` all the way down to
+        // `from`, revisiting the fully-degenerate (0, 0) ring on every extra step.
+        let small: Ellipse<u8> = ellipse!(
+            (0, 0), 2, 2,
+            stroke = Some(1u8),
+            stroke_width = 2,
+            stroke_alignment = StrokeAlignment::Inside,
+        );
+        let oversized: Ellipse<u8> = ellipse!(
+            (0, 0), 2, 2,
+            stroke = Some(1u8),
+            stroke_width = 250,
+            stroke_alignment = StrokeAlignment::Inside,
+        );
+
+        // Once the stroke band reaches past the radii, growing it further can't draw anything
+        // new: both counts should match rather than the oversized one ballooning.
+        assert_eq!(small.into_iter().count(), oversized.into_iter().count());
+    }
+
     #[test]
     fn line() {
         let _l: Line<u8> = line!((10, 20), (30, 40));
@@ -176,6 +602,41 @@ mod tests {
         let _r: Rect<u8> = rect!((10, 20), (30, 40), style = Style::default());
     }
 
+    #[test]
+    fn rect_stroke_width_stroke_alignment_and_corner_radius_compile() {
+        let _r: Rect<u8> = rect!(
+            (0, 0),
+            (10, 10),
+            stroke = Some(1u8),
+            stroke_width = 3,
+            corner_radius = 2,
+        );
+    }
+
+    #[test]
+    fn rect_square_fill_covers_every_pixel() {
+        let square: Rect<u8> = rect!((0, 0), (9, 9), fill = Some(1u8));
+
+        assert_eq!(square.into_iter().count(), 10 * 10);
+    }
+
+    #[test]
+    fn rect_corner_radius_trims_the_corners() {
+        let square: Rect<u8> = rect!((0, 0), (9, 9), fill = Some(1u8));
+        let rounded: Rect<u8> = rect!((0, 0), (9, 9), fill = Some(1u8), corner_radius = 3);
+
+        assert!(rounded.into_iter().count() < square.into_iter().count());
+    }
+
+    #[test]
+    fn rect_corner_radius_is_clamped_to_half_the_smaller_side() {
+        // A corner radius larger than the rectangle itself should clamp down rather than produce
+        // an inverted (left > right, or top > bottom) span.
+        let clamped: Rect<u8> = rect!((0, 0), (9, 9), fill = Some(1u8), corner_radius = 100);
+
+        assert!(clamped.into_iter().count() > 0);
+    }
+
     #[test]
     fn triangle() {
         let _t: Triangle<u8> = triangle!((10, 20), (30, 40), (50, 60));
@@ -188,4 +649,170 @@ mod tests {
         );
         let _t: Triangle<u8> = triangle!((10, 20), (30, 40), (50, 60), style = Style::default());
     }
+
+    #[test]
+    fn polyline() {
+        let _p: Polyline<u8, 3> = polyline!((10, 20), (30, 40), (50, 10));
+        let _p: Polyline<u8, 3> = polyline!((10, 20), (30, 40), (50, 10), stroke = Some(1u8));
+        let _p: Polyline<u8, 3> = polyline!((10, 20), (30, 40), (50, 10), style = Style::default());
+    }
+
+    #[test]
+    fn polyline_segments_connect_all_vertices() {
+        let p: Polyline<u8, 3> = polyline!((0, 0), (10, 0), (10, 10), stroke = Some(1u8));
+
+        // Two 11-pixel segments, sharing (and so both drawing) the middle vertex.
+        assert_eq!(p.into_iter().count(), 22);
+    }
+
+    #[test]
+    fn polygon() {
+        let _p: Polygon<u8, 3> = polygon!((10, 20), (30, 40), (50, 10));
+        let _p: Polygon<u8, 3> =
+            polygon!((10, 20), (30, 40), (50, 10), stroke = Some(1u8), fill = Some(10u8));
+        let _p: Polygon<u8, 3> = polygon!((10, 20), (30, 40), (50, 10), style = Style::default());
+    }
+
+    #[test]
+    fn polygon_accepts_stroke_width_and_alignment() {
+        // Not yet honored by the rasterizer (see `Polygon`'s `WithStyle` impl), but the new
+        // style keys must still thread through the macro.
+        let _p: Polygon<u8, 3> = polygon!(
+            (10, 20), (30, 40), (50, 10),
+            stroke = Some(1u8),
+            stroke_width = 3,
+            stroke_alignment = StrokeAlignment::Outside,
+        );
+    }
+
+    #[test]
+    fn polygon_fill_even_odd() {
+        // A 10x10 square fills every row except the last: the `(y0 <= y) != (y1 <= y)` straddle
+        // test used for the scanline crossings doesn't count either edge meeting at the bottom
+        // two vertices, since both are on the same (upper) side of that scanline.
+        let square: Polygon<u8, 4> =
+            polygon!((0, 0), (9, 0), (9, 9), (0, 9), fill = Some(1u8));
+
+        assert_eq!(square.into_iter().count(), 9 * 10);
+    }
+
+    #[test]
+    fn polygon_fill_is_mirror_symmetric() {
+        use std::collections::HashSet;
+
+        // A mirror-symmetric isosceles triangle has two slanted edges of opposite slope; plain
+        // truncating division in the scanline intersection math rounds them differently, so the
+        // fill used to stick out further on one side than the other.
+        let triangle: Polygon<u8, 3> =
+            polygon!((0, -17), (13, 9), (-13, 9), fill = Some(1u8));
+
+        let pixels: HashSet<(i32, i32)> = triangle
+            .into_iter()
+            .map(|Pixel(coord, _)| (coord.0, coord.1))
+            .collect();
+
+        for &(x, y) in &pixels {
+            assert!(
+                pixels.contains(&(-x, y)),
+                "fill at ({}, {}) has no mirror at ({}, {})",
+                x,
+                y,
+                -x,
+                y,
+            );
+        }
+    }
+
+    #[test]
+    fn path() {
+        let _p: Path<u8> = path!("M10,10 L20,20 Z");
+        let _p: Path<u8> = path!("M10,10 L20,20 Z", stroke = Some(1u8));
+        let _p: Path<u8> = path!("M10,10 L20,20 Z", style = Style::default());
+    }
+
+    #[test]
+    fn path_lineto_and_closepath() {
+        let p: Path<u8> = path!("M0,0 L10,0 L10,10 Z", stroke = Some(1u8));
+
+        // Three drawn edges of 11 pixels each: two linetos plus the closepath back to (0, 0).
+        assert_eq!(p.into_iter().count(), 33);
+    }
+
+    #[test]
+    fn path_relative_commands() {
+        let absolute: Path<u8> = path!("M0,0 L10,0 L10,10 Z", fill = Some(1u8));
+        let relative: Path<u8> = path!("m0,0 l10,0 l0,10 z", fill = Some(1u8));
+
+        assert_eq!(
+            absolute.into_iter().count(),
+            relative.into_iter().count()
+        );
+    }
+
+    #[test]
+    fn path_horizontal_and_vertical_shorthand() {
+        let explicit: Path<u8> = path!("M0,0 L10,0 L10,10 Z", stroke = Some(1u8));
+        let shorthand: Path<u8> = path!("M0,0 H10 V10 Z", stroke = Some(1u8));
+
+        assert_eq!(
+            explicit.into_iter().count(),
+            shorthand.into_iter().count()
+        );
+    }
+
+    #[test]
+    fn path_cubic_bezier_flattens_to_points() {
+        let p: Path<u8> = path!("M0,0 C0,10 10,10 10,0", stroke = Some(1u8));
+
+        assert!(p.into_iter().count() > 1);
+    }
+
+    #[test]
+    fn path_quadratic_bezier_flattens_to_points() {
+        let p: Path<u8> = path!("M0,0 Q5,10 10,0", stroke = Some(1u8));
+
+        assert!(p.into_iter().count() > 1);
+    }
+
+    #[test]
+    fn path_disjoint_subpaths_do_not_connect() {
+        // Two separate 1px squares; if the subpaths were wrongly joined, the fill would also
+        // cover the gap between them.
+        let p: Path<u8> = path!("M0,0 L1,0 L1,1 L0,1 Z M10,0 L11,0 L11,1 L10,1 Z", fill = Some(1u8));
+
+        for Pixel(point, _) in p.into_iter() {
+            assert!(point.0 <= 1 || point.0 >= 10);
+        }
+    }
+
+    #[test]
+    fn primitives_from_each_shape() {
+        let _p: Primitives<u8> = Circle::new(Coord::new(10, 20), 30).into();
+        let _p: Primitives<u8> = Line::new(Coord::new(10, 20), Coord::new(30, 40)).into();
+        let _p: Primitives<u8> = Rect::new(Coord::new(10, 20), Coord::new(30, 40)).into();
+        let _p: Primitives<u8> =
+            Triangle::new(Coord::new(10, 20), Coord::new(30, 40), Coord::new(50, 60)).into();
+    }
+
+    #[test]
+    fn primitives_macros() {
+        let _p: Primitives<u8> = circle!(@primitive (10, 20), 30);
+        let _p: Primitives<u8> = line!(@primitive (10, 20), (30, 40));
+        let _p: Primitives<u8> = rect!(@primitive (10, 20), (30, 40));
+        let _p: Primitives<u8> = triangle!(@primitive (10, 20), (30, 40), (50, 60));
+    }
+
+    #[test]
+    fn primitives_mixed_vec_into_iter() {
+        let shapes: Vec<Primitives<u8>> = vec![
+            circle!(@primitive (10, 20), 30),
+            line!(@primitive (10, 20), (30, 40)),
+            rect!(@primitive (10, 20), (30, 40)),
+            triangle!(@primitive (10, 20), (30, 40), (50, 60)),
+        ];
+
+        for shape in shapes {
+            for _pixel in shape {}
+        }
+    }
 }
@@ -0,0 +1,587 @@
+//! The SVG-style path primitive
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::bresenham::Bresenham;
+use crate::primitives::polyline::bounding_box;
+use crate::primitives::Primitive;
+use crate::style::{Style, StrokeAlignment, WithStyle};
+
+/// The default point capacity for a [`Path`](struct.Path.html) when none is given explicitly.
+///
+/// Curves are flattened to line segments at parse time, so this bounds the total number of
+/// moveto/lineto/flattened-curve points across every subpath in the `d` string. Paths with more
+/// points than this are silently truncated; pick a larger `N` (`Path<C, 256>`, say) for more
+/// detailed artwork.
+pub const DEFAULT_PATH_CAPACITY: usize = 128;
+
+/// An SVG-style vector path, built by parsing a subset of the `d` attribute grammar
+/// (`M`/`m`, `L`/`l`, `H`/`h`, `V`/`v`, `C`/`c`, `Q`/`q`, `Z`/`z`).
+///
+/// Like [`Polyline`](../polyline/struct.Polyline.html) and [`Polygon`](../polygon/struct.Polygon.html),
+/// the flattened points are stored inline in a fixed-capacity array (`N`, defaulting to
+/// [`DEFAULT_PATH_CAPACITY`](constant.DEFAULT_PATH_CAPACITY.html)) rather than on the heap.
+/// Bézier curves are flattened to line segments by evaluating the curve at a number of steps
+/// chosen from the length of its control polygon, so each segment stays roughly 1px long; the
+/// step count is capped at 64 per curve, so curves with a longer control polygon get longer
+/// segments.
+///
+/// ```rust
+/// use embedded_graphics::primitives::Path;
+///
+/// let path: Path<u8> = Path::parse("M10,10 L20,20 Z");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Path<C: PixelColor, const N: usize = DEFAULT_PATH_CAPACITY> {
+    points: [Coord; N],
+    /// `move_flags[i]` is `true` when `points[i]` is the first point of a new subpath (i.e. it
+    /// was produced by a moveto command).
+    move_flags: [bool; N],
+    /// For each point `i`, the index of the point that closes its subpath for fill purposes:
+    /// either `i + 1` (if that point continues the same subpath) or the subpath's own start
+    /// index (implicitly closing it, per the SVG even-odd fill rule).
+    fill_next: [usize; N],
+    len: usize,
+    /// Object style
+    pub style: Style<C>,
+}
+
+impl<C, const N: usize> Path<C, N>
+where
+    C: PixelColor,
+{
+    /// Parse an SVG-like path-data string (the grammar of the `d` attribute) into a `Path`.
+    ///
+    /// Unsupported or malformed trailing commands stop parsing early rather than panicking;
+    /// whatever subpaths were parsed so far are kept.
+    pub fn parse(d: &str) -> Self {
+        let mut points = [Coord::new(0, 0); N];
+        let mut move_flags = [false; N];
+        let mut len = 0usize;
+
+        let mut scanner = Scanner::new(d);
+        let mut current = Coord::new(0, 0);
+        let mut subpath_start = Coord::new(0, 0);
+        let mut command: Option<char> = None;
+
+        loop {
+            let cmd = match scanner.peek() {
+                Some(b) if b.is_ascii_alphabetic() => {
+                    command = scanner.next_command();
+                    match command {
+                        Some(c) => c,
+                        None => break,
+                    }
+                }
+                Some(_) => match command {
+                    Some(c) => c,
+                    None => break,
+                },
+                None => break,
+            };
+
+            match cmd {
+                'M' | 'm' => {
+                    let (Some(x), Some(y)) = (scanner.next_number(), scanner.next_number()) else {
+                        break;
+                    };
+                    current = relative(cmd == 'm', current, x, y);
+                    subpath_start = current;
+                    push_point(&mut points, &mut move_flags, &mut len, current, true);
+                    // A second coordinate pair without a repeated command letter is an
+                    // implicit lineto.
+                    command = Some(if cmd == 'm' { 'l' } else { 'L' });
+                }
+                'L' | 'l' => {
+                    let (Some(x), Some(y)) = (scanner.next_number(), scanner.next_number()) else {
+                        break;
+                    };
+                    current = relative(cmd == 'l', current, x, y);
+                    push_point(&mut points, &mut move_flags, &mut len, current, false);
+                }
+                'H' | 'h' => {
+                    let Some(x) = scanner.next_number() else {
+                        break;
+                    };
+                    current = Coord::new(
+                        if cmd == 'h' {
+                            current.0 + x as i32
+                        } else {
+                            x as i32
+                        },
+                        current.1,
+                    );
+                    push_point(&mut points, &mut move_flags, &mut len, current, false);
+                }
+                'V' | 'v' => {
+                    let Some(y) = scanner.next_number() else {
+                        break;
+                    };
+                    current = Coord::new(
+                        current.0,
+                        if cmd == 'v' {
+                            current.1 + y as i32
+                        } else {
+                            y as i32
+                        },
+                    );
+                    push_point(&mut points, &mut move_flags, &mut len, current, false);
+                }
+                'C' | 'c' => {
+                    let (
+                        Some(x1),
+                        Some(y1),
+                        Some(x2),
+                        Some(y2),
+                        Some(x),
+                        Some(y),
+                    ) = (
+                        scanner.next_number(),
+                        scanner.next_number(),
+                        scanner.next_number(),
+                        scanner.next_number(),
+                        scanner.next_number(),
+                        scanner.next_number(),
+                    ) else {
+                        break;
+                    };
+                    let relative_cmd = cmd == 'c';
+                    let p1 = relative(relative_cmd, current, x1, y1);
+                    let p2 = relative(relative_cmd, current, x2, y2);
+                    let p3 = relative(relative_cmd, current, x, y);
+                    flatten_cubic(&mut points, &mut move_flags, &mut len, current, p1, p2, p3);
+                    current = p3;
+                }
+                'Q' | 'q' => {
+                    let (Some(x1), Some(y1), Some(x), Some(y)) = (
+                        scanner.next_number(),
+                        scanner.next_number(),
+                        scanner.next_number(),
+                        scanner.next_number(),
+                    ) else {
+                        break;
+                    };
+                    let relative_cmd = cmd == 'q';
+                    let p1 = relative(relative_cmd, current, x1, y1);
+                    let p2 = relative(relative_cmd, current, x, y);
+                    flatten_quadratic(&mut points, &mut move_flags, &mut len, current, p1, p2);
+                    current = p2;
+                }
+                'Z' | 'z' => {
+                    current = subpath_start;
+                    push_point(&mut points, &mut move_flags, &mut len, current, false);
+                    command = None;
+                }
+                _ => break,
+            }
+        }
+
+        let fill_next = close_subpaths(&move_flags, len);
+
+        Path {
+            points,
+            move_flags,
+            fill_next,
+            len,
+            style: Style::default(),
+        }
+    }
+}
+
+impl<C, const N: usize> Primitive for Path<C, N> where C: PixelColor {}
+
+impl<C, const N: usize> Dimensions for Path<C, N>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        bounding_box(&self.points[..self.len]).0
+    }
+
+    fn bottom_right(&self) -> Coord {
+        bounding_box(&self.points[..self.len]).1
+    }
+
+    fn size(&self) -> Coord {
+        self.bottom_right() - self.top_left()
+    }
+}
+
+impl<C, const N: usize> WithStyle<C> for Path<C, N>
+where
+    C: PixelColor,
+{
+    fn style(self, style: Style<C>) -> Self {
+        Path { style, ..self }
+    }
+
+    fn stroke(self, color: Option<C>) -> Self {
+        Path {
+            style: Style {
+                stroke_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    // Accepted for consistency with the other primitives; the edges are still rasterized as 1px
+    // Bresenham lines, so width/alignment have no visual effect yet.
+    fn stroke_width(self, width: u8) -> Self {
+        Path {
+            style: Style {
+                stroke_width: width,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn stroke_alignment(self, alignment: StrokeAlignment) -> Self {
+        Path {
+            style: Style {
+                stroke_alignment: alignment,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn fill(self, color: Option<C>) -> Self {
+        Path {
+            style: Style {
+                fill_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+}
+
+/// A minimal scanner over the `d` attribute grammar: command letters and whitespace/comma
+/// separated numbers (no exponent support).
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(d: &'a str) -> Self {
+        Scanner {
+            bytes: d.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(&b) = self.bytes.get(self.pos) {
+            match b {
+                b' ' | b'\t' | b'\r' | b'\n' | b',' => self.pos += 1,
+                _ => break,
+            }
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn next_command(&mut self) -> Option<char> {
+        match self.peek() {
+            Some(b) if b.is_ascii_alphabetic() => {
+                self.pos += 1;
+                Some(b as char)
+            }
+            _ => None,
+        }
+    }
+
+    fn next_number(&mut self) -> Option<f32> {
+        self.skip_separators();
+        let start = self.pos;
+
+        if matches!(self.bytes.get(self.pos), Some(b'-') | Some(b'+')) {
+            self.pos += 1;
+        }
+
+        let digits_start = self.pos;
+        while matches!(self.bytes.get(self.pos), Some(b) if b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if self.bytes.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            while matches!(self.bytes.get(self.pos), Some(b) if b.is_ascii_digit()) {
+                self.pos += 1;
+            }
+        }
+
+        if self.pos == digits_start {
+            self.pos = start;
+            return None;
+        }
+
+        core::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()?
+            .parse()
+            .ok()
+    }
+}
+
+fn relative(is_relative: bool, current: Coord, x: f32, y: f32) -> Coord {
+    if is_relative {
+        Coord::new(current.0 + x as i32, current.1 + y as i32)
+    } else {
+        Coord::new(x as i32, y as i32)
+    }
+}
+
+fn push_point<const N: usize>(
+    points: &mut [Coord; N],
+    move_flags: &mut [bool; N],
+    len: &mut usize,
+    point: Coord,
+    is_move: bool,
+) {
+    if *len < N {
+        points[*len] = point;
+        move_flags[*len] = is_move;
+        *len += 1;
+    }
+}
+
+fn manhattan(a: Coord, b: Coord) -> i32 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+/// Flattens a cubic Bézier `P0..P3` into line segments, appending them (excluding `p0`, which is
+/// assumed already present) to the point buffer.
+fn flatten_cubic<const N: usize>(
+    points: &mut [Coord; N],
+    move_flags: &mut [bool; N],
+    len: &mut usize,
+    p0: Coord,
+    p1: Coord,
+    p2: Coord,
+    p3: Coord,
+) {
+    let approx_len = manhattan(p0, p1) + manhattan(p1, p2) + manhattan(p2, p3);
+    let steps = approx_len.clamp(1, 64) as i64;
+
+    for k in 1..=steps {
+        let nk = steps - k;
+        let n3 = steps * steps * steps;
+        let x = (nk * nk * nk * p0.0 as i64
+            + 3 * nk * nk * k * p1.0 as i64
+            + 3 * nk * k * k * p2.0 as i64
+            + k * k * k * p3.0 as i64)
+            / n3;
+        let y = (nk * nk * nk * p0.1 as i64
+            + 3 * nk * nk * k * p1.1 as i64
+            + 3 * nk * k * k * p2.1 as i64
+            + k * k * k * p3.1 as i64)
+            / n3;
+        push_point(
+            points,
+            move_flags,
+            len,
+            Coord::new(x as i32, y as i32),
+            false,
+        );
+    }
+}
+
+/// Flattens a quadratic Bézier `P0..P2` the same way as [`flatten_cubic`](fn.flatten_cubic.html).
+fn flatten_quadratic<const N: usize>(
+    points: &mut [Coord; N],
+    move_flags: &mut [bool; N],
+    len: &mut usize,
+    p0: Coord,
+    p1: Coord,
+    p2: Coord,
+) {
+    let approx_len = manhattan(p0, p1) + manhattan(p1, p2);
+    let steps = approx_len.clamp(1, 64) as i64;
+
+    for k in 1..=steps {
+        let nk = steps - k;
+        let n2 = steps * steps;
+        let x = (nk * nk * p0.0 as i64 + 2 * nk * k * p1.0 as i64 + k * k * p2.0 as i64) / n2;
+        let y = (nk * nk * p0.1 as i64 + 2 * nk * k * p1.1 as i64 + k * k * p2.1 as i64) / n2;
+        push_point(
+            points,
+            move_flags,
+            len,
+            Coord::new(x as i32, y as i32),
+            false,
+        );
+    }
+}
+
+/// For every point, finds the index that closes its subpath for fill purposes: the next point if
+/// it continues the same subpath, or the subpath's own start index otherwise. This implicitly
+/// closes every subpath for fill, regardless of whether the `d` string used `Z`.
+fn close_subpaths<const N: usize>(move_flags: &[bool; N], len: usize) -> [usize; N] {
+    let mut fill_next = [0usize; N];
+    let mut subpath_start = 0usize;
+
+    for i in 0..len {
+        if move_flags[i] {
+            subpath_start = i;
+        }
+        let is_last_of_subpath = i + 1 >= len || move_flags[i + 1];
+        fill_next[i] = if is_last_of_subpath { subpath_start } else { i + 1 };
+    }
+
+    fill_next
+}
+
+fn path_intersections<const N: usize>(
+    points: &[Coord; N],
+    fill_next: &[usize; N],
+    len: usize,
+    y: i32,
+    xs: &mut [i32; N],
+) -> usize {
+    let mut count = 0;
+
+    for i in 0..len {
+        let p0 = points[i];
+        let p1 = points[fill_next[i]];
+
+        if (p0.1 <= y) != (p1.1 <= y) {
+            // See `polygon::intersections` for why this needs `div_euclid` rather than plain `/`.
+            let x = p0.0 + ((y - p0.1) * (p1.0 - p0.0)).div_euclid(p1.1 - p0.1);
+            xs[count] = x;
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// Pixel iterator for the `Path` primitive: an even-odd scanline fill across all subpaths
+/// (implicitly closed), followed by the stroked edges actually present in the parsed path.
+#[derive(Debug, Clone)]
+pub struct PathIterator<C: PixelColor, const N: usize> {
+    points: [Coord; N],
+    move_flags: [bool; N],
+    fill_next: [usize; N],
+    len: usize,
+    style: Style<C>,
+    max_y: i32,
+    y: i32,
+    xs: [i32; N],
+    xs_len: usize,
+    pair: usize,
+    cur_x: i32,
+    filling: bool,
+    edge: usize,
+    current_edge: Option<Bresenham>,
+}
+
+impl<C, const N: usize> Iterator for PathIterator<C, N>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.filling {
+                if self.style.fill_color.is_none() || self.len == 0 {
+                    self.filling = false;
+                    continue;
+                }
+
+                if self.pair * 2 + 1 < self.xs_len {
+                    let to = self.xs[self.pair * 2 + 1];
+
+                    if self.cur_x <= to {
+                        let point = Coord::new(self.cur_x, self.y);
+                        self.cur_x += 1;
+                        return Some(Pixel(point, self.style.fill_color.unwrap()));
+                    }
+
+                    self.pair += 1;
+                    if self.pair * 2 + 1 < self.xs_len {
+                        self.cur_x = self.xs[self.pair * 2];
+                    }
+                    continue;
+                }
+
+                self.y += 1;
+                if self.y > self.max_y {
+                    self.filling = false;
+                    continue;
+                }
+
+                self.xs_len =
+                    path_intersections(&self.points, &self.fill_next, self.len, self.y, &mut self.xs);
+                self.xs[..self.xs_len].sort_unstable();
+                self.pair = 0;
+                if self.xs_len >= 2 {
+                    self.cur_x = self.xs[0];
+                }
+                continue;
+            }
+
+            if let Some(bresenham) = &mut self.current_edge {
+                if let Some(point) = bresenham.next() {
+                    if let Some(color) = self.style.stroke_color {
+                        return Some(Pixel(point, color));
+                    } else {
+                        continue;
+                    }
+                } else {
+                    self.current_edge = None;
+                }
+            }
+
+            let mut found = None;
+            while self.edge + 1 < self.len {
+                let i = self.edge;
+                self.edge += 1;
+                if !self.move_flags[i + 1] {
+                    found = Some((self.points[i], self.points[i + 1]));
+                    break;
+                }
+            }
+
+            match found {
+                Some((a, b)) => self.current_edge = Some(Bresenham::new(a, b)),
+                None => return None,
+            }
+        }
+    }
+}
+
+impl<C, const N: usize> IntoIterator for Path<C, N>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PathIterator<C, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (top_left, bottom_right) = bounding_box(&self.points[..self.len]);
+
+        PathIterator {
+            points: self.points,
+            move_flags: self.move_flags,
+            fill_next: self.fill_next,
+            len: self.len,
+            style: self.style,
+            max_y: bottom_right.1,
+            y: top_left.1 - 1,
+            xs: [0; N],
+            xs_len: 0,
+            pair: 0,
+            cur_x: 0,
+            filling: true,
+            edge: 0,
+            current_edge: None,
+        }
+    }
+}
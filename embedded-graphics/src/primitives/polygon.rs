@@ -0,0 +1,257 @@
+//! The filled polygon primitive
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::bresenham::Bresenham;
+use crate::primitives::polyline::bounding_box;
+use crate::primitives::Primitive;
+use crate::style::{Style, StrokeAlignment, WithStyle};
+
+/// A closed shape connecting `N` vertices in order, with the last vertex joined back to the
+/// first.
+///
+/// Like [`Polyline`](../polyline/struct.Polyline.html), the vertex count is a const generic so
+/// the vertices are stored inline without heap allocation. Unlike `Polyline`, `Polygon` supports
+/// `fill` using an even-odd scanline fill, with the stroke (if any) drawn over the filled edges
+/// afterwards.
+///
+/// ```rust
+/// use embedded_graphics::primitives::Polygon;
+/// use embedded_graphics::coord::Coord;
+///
+/// let triangle: Polygon<u8, 3> = Polygon::new([
+///     Coord::new(10, 20),
+///     Coord::new(30, 40),
+///     Coord::new(50, 10),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Polygon<C: PixelColor, const N: usize> {
+    /// The vertices, connected in order with the last implicitly joined to the first
+    pub vertices: [Coord; N],
+    /// Object style
+    pub style: Style<C>,
+}
+
+impl<C, const N: usize> Polygon<C, N>
+where
+    C: PixelColor,
+{
+    /// Create a new polygon connecting `vertices` in order, closing the last vertex back to the
+    /// first
+    pub fn new(vertices: [Coord; N]) -> Self {
+        Polygon {
+            vertices,
+            style: Style::default(),
+        }
+    }
+}
+
+impl<C, const N: usize> Primitive for Polygon<C, N> where C: PixelColor {}
+
+impl<C, const N: usize> Dimensions for Polygon<C, N>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        bounding_box(&self.vertices).0
+    }
+
+    fn bottom_right(&self) -> Coord {
+        bounding_box(&self.vertices).1
+    }
+
+    fn size(&self) -> Coord {
+        self.bottom_right() - self.top_left()
+    }
+}
+
+impl<C, const N: usize> WithStyle<C> for Polygon<C, N>
+where
+    C: PixelColor,
+{
+    fn style(self, style: Style<C>) -> Self {
+        Polygon { style, ..self }
+    }
+
+    fn stroke(self, color: Option<C>) -> Self {
+        Polygon {
+            style: Style {
+                stroke_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    // `stroke_width` and `stroke_alignment` are accepted for consistency with the other
+    // primitives, but the edges are still rasterized as 1px Bresenham lines; see
+    // `Ellipse`'s concentric-ring stroke for the primitive that currently honors them.
+    fn stroke_width(self, width: u8) -> Self {
+        Polygon {
+            style: Style {
+                stroke_width: width,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn stroke_alignment(self, alignment: StrokeAlignment) -> Self {
+        Polygon {
+            style: Style {
+                stroke_alignment: alignment,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn fill(self, color: Option<C>) -> Self {
+        Polygon {
+            style: Style {
+                fill_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+}
+
+/// Finds the x-intersections of every edge straddling scanline `y`, writing them into `xs` and
+/// returning how many were found. Edges exactly on `y` at one endpoint are handled by the
+/// `(y0 <= y) != (y1 <= y)` test, which treats `y` as belonging to the lower of the two edges
+/// meeting at any shared vertex.
+fn intersections<const N: usize>(vertices: &[Coord; N], y: i32, xs: &mut [i32; N]) -> usize {
+    let mut count = 0;
+
+    for i in 0..N {
+        let p0 = vertices[i];
+        let p1 = vertices[(i + 1) % N];
+
+        if (p0.1 <= y) != (p1.1 <= y) {
+            // `div_euclid` rounds the quotient down for an edge running toward larger `y` and up
+            // for one running toward smaller `y`, instead of truncating toward zero like plain
+            // `/` — without it, the fill boundary shifts depending on whether an edge slopes left
+            // or right, making symmetric shapes fill asymmetrically.
+            let x = p0.0 + ((y - p0.1) * (p1.0 - p0.0)).div_euclid(p1.1 - p0.1);
+            xs[count] = x;
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// Pixel iterator for the `Polygon` primitive: an even-odd scanline fill, followed by the
+/// stroked edges.
+#[derive(Debug, Clone)]
+pub struct PolygonIterator<C: PixelColor, const N: usize> {
+    vertices: [Coord; N],
+    style: Style<C>,
+    max_y: i32,
+    y: i32,
+    xs: [i32; N],
+    xs_len: usize,
+    pair: usize,
+    cur_x: i32,
+    filling: bool,
+    edge: usize,
+    current_edge: Option<Bresenham>,
+}
+
+impl<C, const N: usize> Iterator for PolygonIterator<C, N>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.filling {
+                if self.style.fill_color.is_none() || N < 3 {
+                    self.filling = false;
+                    continue;
+                }
+
+                if self.pair * 2 + 1 < self.xs_len {
+                    let to = self.xs[self.pair * 2 + 1];
+
+                    if self.cur_x <= to {
+                        let point = Coord::new(self.cur_x, self.y);
+                        self.cur_x += 1;
+                        return Some(Pixel(point, self.style.fill_color.unwrap()));
+                    }
+
+                    self.pair += 1;
+                    if self.pair * 2 + 1 < self.xs_len {
+                        self.cur_x = self.xs[self.pair * 2];
+                    }
+                    continue;
+                }
+
+                self.y += 1;
+                if self.y > self.max_y {
+                    self.filling = false;
+                    continue;
+                }
+
+                self.xs_len = intersections(&self.vertices, self.y, &mut self.xs);
+                self.xs[..self.xs_len].sort_unstable();
+                self.pair = 0;
+                if self.xs_len >= 2 {
+                    self.cur_x = self.xs[0];
+                }
+                continue;
+            }
+
+            if let Some(bresenham) = &mut self.current_edge {
+                if let Some(point) = bresenham.next() {
+                    if let Some(color) = self.style.stroke_color {
+                        return Some(Pixel(point, color));
+                    } else {
+                        continue;
+                    }
+                } else {
+                    self.current_edge = None;
+                }
+            }
+
+            if N < 2 || self.edge >= N {
+                return None;
+            }
+
+            let a = self.vertices[self.edge];
+            let b = self.vertices[(self.edge + 1) % N];
+            self.current_edge = Some(Bresenham::new(a, b));
+            self.edge += 1;
+        }
+    }
+}
+
+impl<C, const N: usize> IntoIterator for Polygon<C, N>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PolygonIterator<C, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (top_left, bottom_right) = bounding_box(&self.vertices);
+
+        PolygonIterator {
+            vertices: self.vertices,
+            style: self.style,
+            max_y: bottom_right.1,
+            y: top_left.1 - 1,
+            xs: [0; N],
+            xs_len: 0,
+            pair: 0,
+            cur_x: 0,
+            filling: true,
+            edge: 0,
+            current_edge: None,
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! The polyline primitive
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::bresenham::Bresenham;
+use crate::primitives::Primitive;
+use crate::style::{Style, StrokeAlignment, WithStyle};
+
+/// An open, multi-segment line connecting `N` vertices in order.
+///
+/// Unlike [`Triangle`](../triangle/struct.Triangle.html), which is fixed at 3 points, `Polyline`
+/// accepts any number of vertices, making it useful for charts, maps, or other line-based data.
+/// The vertex count is tracked as a const generic so the vertices can be stored inline, without
+/// heap allocation.
+///
+/// Note that, like [`Line`](../line/struct.Line.html), only the `stroke` property has any effect;
+/// a polyline is never filled.
+///
+/// ```rust
+/// use embedded_graphics::primitives::Polyline;
+/// use embedded_graphics::coord::Coord;
+///
+/// let polyline: Polyline<u8, 3> = Polyline::new([
+///     Coord::new(10, 20),
+///     Coord::new(30, 40),
+///     Coord::new(50, 10),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Polyline<C: PixelColor, const N: usize> {
+    /// The vertices, connected in order
+    pub vertices: [Coord; N],
+    /// Object style
+    pub style: Style<C>,
+}
+
+impl<C, const N: usize> Polyline<C, N>
+where
+    C: PixelColor,
+{
+    /// Create a new polyline connecting `vertices` in order
+    pub fn new(vertices: [Coord; N]) -> Self {
+        Polyline {
+            vertices,
+            style: Style::default(),
+        }
+    }
+}
+
+impl<C, const N: usize> Primitive for Polyline<C, N> where C: PixelColor {}
+
+impl<C, const N: usize> Dimensions for Polyline<C, N>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        bounding_box(&self.vertices).0
+    }
+
+    fn bottom_right(&self) -> Coord {
+        bounding_box(&self.vertices).1
+    }
+
+    fn size(&self) -> Coord {
+        self.bottom_right() - self.top_left()
+    }
+}
+
+impl<C, const N: usize> WithStyle<C> for Polyline<C, N>
+where
+    C: PixelColor,
+{
+    fn style(self, style: Style<C>) -> Self {
+        Polyline { style, ..self }
+    }
+
+    fn stroke(self, color: Option<C>) -> Self {
+        Polyline {
+            style: Style {
+                stroke_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    // Accepted for consistency with the other primitives; the segments are still rasterized as
+    // 1px Bresenham lines, so width/alignment have no visual effect yet.
+    fn stroke_width(self, width: u8) -> Self {
+        Polyline {
+            style: Style {
+                stroke_width: width,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn stroke_alignment(self, alignment: StrokeAlignment) -> Self {
+        Polyline {
+            style: Style {
+                stroke_alignment: alignment,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn fill(self, color: Option<C>) -> Self {
+        Polyline {
+            style: Style {
+                fill_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+}
+
+/// Computes the min/max corners of a vertex list, defaulting to the origin when empty.
+pub(crate) fn bounding_box(vertices: &[Coord]) -> (Coord, Coord) {
+    let mut iter = vertices.iter();
+    let first = match iter.next() {
+        Some(v) => *v,
+        None => return (Coord::new(0, 0), Coord::new(0, 0)),
+    };
+
+    let mut min = first;
+    let mut max = first;
+
+    for &v in iter {
+        min = Coord::new(min.0.min(v.0), min.1.min(v.1));
+        max = Coord::new(max.0.max(v.0), max.1.max(v.1));
+    }
+
+    (min, max)
+}
+
+/// Pixel iterator for the `Polyline` primitive.
+#[derive(Debug, Clone)]
+pub struct PolylineIterator<C: PixelColor, const N: usize> {
+    vertices: [Coord; N],
+    style: Style<C>,
+    segment: usize,
+    current: Option<Bresenham>,
+}
+
+impl<C, const N: usize> Iterator for PolylineIterator<C, N>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(bresenham) = &mut self.current {
+                if let Some(point) = bresenham.next() {
+                    if let Some(color) = self.style.stroke_color {
+                        return Some(Pixel(point, color));
+                    } else {
+                        continue;
+                    }
+                } else {
+                    self.current = None;
+                }
+            }
+
+            if self.segment + 1 >= N {
+                return None;
+            }
+
+            self.current = Some(Bresenham::new(
+                self.vertices[self.segment],
+                self.vertices[self.segment + 1],
+            ));
+            self.segment += 1;
+        }
+    }
+}
+
+impl<C, const N: usize> IntoIterator for Polyline<C, N>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = PolylineIterator<C, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PolylineIterator {
+            vertices: self.vertices,
+            style: self.style,
+            segment: 0,
+            current: None,
+        }
+    }
+}
@@ -0,0 +1,322 @@
+//! The rectangle primitive
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::ellipse::{MidpointEllipse, Span};
+use crate::primitives::Primitive;
+use crate::style::{stroke_offsets, Style, StrokeAlignment, WithStyle};
+
+/// An axis-aligned rectangle, described by its top-left and bottom-right corners.
+///
+/// ```rust
+/// use embedded_graphics::primitives::Rect;
+/// use embedded_graphics::coord::Coord;
+///
+/// let rect: Rect<u8> = Rect::new(Coord::new(10, 20), Coord::new(30, 40));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Rect<C: PixelColor> {
+    /// Top-left corner
+    pub top_left: Coord,
+    /// Bottom-right corner
+    pub bottom_right: Coord,
+    /// Corner radius, in pixels. `0` (the default) draws square corners.
+    pub corner_radius: u32,
+    /// Object style
+    pub style: Style<C>,
+}
+
+impl<C> Rect<C>
+where
+    C: PixelColor,
+{
+    /// Create a new rectangle spanning `p0` to `p1`
+    pub fn new(p0: Coord, p1: Coord) -> Self {
+        Rect {
+            top_left: Coord::new(p0.0.min(p1.0), p0.1.min(p1.1)),
+            bottom_right: Coord::new(p0.0.max(p1.0), p0.1.max(p1.1)),
+            corner_radius: 0,
+            style: Style::default(),
+        }
+    }
+
+    /// Round the corners by `radius` pixels, rasterized as arcs of the midpoint-ellipse routine
+    /// (as a circle, since a corner's `rx` and `ry` are equal).
+    pub fn corner_radius(self, radius: u32) -> Self {
+        Rect {
+            corner_radius: radius,
+            ..self
+        }
+    }
+}
+
+impl<C> Primitive for Rect<C> where C: PixelColor {}
+
+impl<C> Dimensions for Rect<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.top_left
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.bottom_right
+    }
+
+    fn size(&self) -> Coord {
+        self.bottom_right - self.top_left
+    }
+}
+
+impl<C> WithStyle<C> for Rect<C>
+where
+    C: PixelColor,
+{
+    fn style(self, style: Style<C>) -> Self {
+        Rect { style, ..self }
+    }
+
+    fn stroke(self, color: Option<C>) -> Self {
+        Rect {
+            style: Style {
+                stroke_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn stroke_width(self, width: u8) -> Self {
+        Rect {
+            style: Style {
+                stroke_width: width,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn stroke_alignment(self, alignment: StrokeAlignment) -> Self {
+        Rect {
+            style: Style {
+                stroke_alignment: alignment,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn fill(self, color: Option<C>) -> Self {
+        Rect {
+            style: Style {
+                fill_color: color,
+                ..self.style
+            },
+            ..self
+        }
+    }
+}
+
+/// For each vertical offset `dy` from a rounded corner's circular center (`0..=radius`), the
+/// horizontal inset of that corner's arc at that row — reusing the same integer midpoint-ellipse
+/// walk `Ellipse` uses, as a circle (`rx == ry == radius`) special case.
+fn corner_insets(radius: i32) -> Vec<i32> {
+    let r = radius as i64;
+    let mut insets = vec![radius; (r + 1) as usize];
+    let mut mid = MidpointEllipse::new(r, r);
+
+    while let Some((x, y)) = mid.next() {
+        let inset = (r - x) as i32;
+        let slot = &mut insets[y as usize];
+        *slot = (*slot).min(inset);
+    }
+
+    insets
+}
+
+/// One concentric "ring" of the rectangle's outline (a rectangle of given bounds and corner
+/// radius), walked a row at a time. Mirrors `Ellipse`'s ring-based approach to stroke width: the
+/// flat top and bottom rows are filled spans (the whole edge), while every row in between is just
+/// its two boundary points (the left and right edges, curving inward through the corner rows).
+#[derive(Debug, Clone)]
+struct RectRing<C: PixelColor> {
+    top: i32,
+    bottom: i32,
+    left: i32,
+    right: i32,
+    radius: i32,
+    insets: Vec<i32>,
+    filled: bool,
+    color: C,
+    y: i32,
+}
+
+impl<C: PixelColor> RectRing<C> {
+    fn new(top: i32, bottom: i32, left: i32, right: i32, radius: i32, filled: bool, color: C) -> Self {
+        let radius = radius.max(0).min((right - left) / 2).min((bottom - top) / 2).max(0);
+
+        RectRing {
+            top,
+            bottom,
+            left,
+            right,
+            radius,
+            insets: corner_insets(radius),
+            filled,
+            color,
+            y: top,
+        }
+    }
+
+    fn row_inset(&self, y: i32) -> i32 {
+        if self.radius == 0 {
+            0
+        } else if y < self.top + self.radius {
+            self.insets[(self.top + self.radius - y).clamp(0, self.radius) as usize]
+        } else if y > self.bottom - self.radius {
+            self.insets[(y - (self.bottom - self.radius)).clamp(0, self.radius) as usize]
+        } else {
+            0
+        }
+    }
+
+    /// Returns the next row's span, or `None` once every row (`top..=bottom`) has been walked.
+    fn next_span(&mut self) -> Option<Span<C>> {
+        if self.left > self.right || self.top > self.bottom || self.y > self.bottom {
+            return None;
+        }
+
+        let y = self.y;
+        self.y += 1;
+        let inset = self.row_inset(y);
+
+        Some(Span {
+            primary: y,
+            from: self.left + inset,
+            to: self.right - inset,
+            cur: self.left + inset,
+            filled: self.filled || y == self.top || y == self.bottom,
+            vertical: false,
+            color: self.color,
+        })
+    }
+}
+
+/// Pixel iterator for the `Rect` primitive.
+///
+/// Renders as a (clamped) sequence of rings, the same way `Ellipse` does: first the solid fill,
+/// inset to the inner edge of the stroke band, then one ring per pixel offset in the stroke band.
+#[derive(Debug, Clone)]
+pub struct RectIterator<C: PixelColor> {
+    top_left: Coord,
+    bottom_right: Coord,
+    radius: i32,
+    fill_color: Option<C>,
+    fill_offset: i32,
+    fill_pending: bool,
+    stroke_color: Option<C>,
+    stroke_k: i32,
+    stroke_to: i32,
+    current: Option<RectRing<C>>,
+    span: Option<Span<C>>,
+}
+
+impl<C: PixelColor> RectIterator<C> {
+    fn ring_bounds(&self, k: i32) -> (i32, i32, i32, i32, i32) {
+        (
+            self.top_left.1 - k,
+            self.bottom_right.1 + k,
+            self.top_left.0 - k,
+            self.bottom_right.0 + k,
+            self.radius + k,
+        )
+    }
+}
+
+impl<C> Iterator for RectIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(span) = self.span.as_mut() {
+                if let Some((x, y)) = span.next_point() {
+                    return Some(Pixel(Coord::new(x, y), span.color));
+                } else {
+                    self.span = None;
+                }
+            }
+
+            if let Some(ring) = self.current.as_mut() {
+                if let Some(span) = ring.next_span() {
+                    self.span = Some(span);
+                    continue;
+                } else {
+                    self.current = None;
+                }
+            }
+
+            if self.fill_pending {
+                self.fill_pending = false;
+                if let Some(color) = self.fill_color {
+                    let (top, bottom, left, right, radius) = self.ring_bounds(self.fill_offset);
+                    self.current = Some(RectRing::new(top, bottom, left, right, radius, true, color));
+                    continue;
+                }
+            }
+
+            if let Some(color) = self.stroke_color {
+                if self.stroke_k <= self.stroke_to {
+                    let k = self.stroke_k;
+                    self.stroke_k += 1;
+                    let (top, bottom, left, right, radius) = self.ring_bounds(k);
+                    self.current = Some(RectRing::new(top, bottom, left, right, radius, false, color));
+                    continue;
+                }
+            }
+
+            return None;
+        }
+    }
+}
+
+impl<C> IntoIterator for Rect<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = RectIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let width = self.bottom_right.0 - self.top_left.0;
+        let height = self.bottom_right.1 - self.top_left.1;
+
+        let (from, to) = if self.style.stroke_color.is_some() {
+            stroke_offsets(self.style.stroke_width, self.style.stroke_alignment)
+        } else {
+            (0, 0)
+        };
+
+        // Past `-(width/2).min(height/2)`, the ring has already shrunk to nothing, so every
+        // further step would re-emit the same empty ring (see `Ellipse`'s analogous clamp).
+        let stroke_k = from.max(-((width / 2).min(height / 2)));
+
+        RectIterator {
+            top_left: self.top_left,
+            bottom_right: self.bottom_right,
+            radius: self.corner_radius as i32,
+            fill_color: self.style.fill_color,
+            fill_offset: from,
+            fill_pending: true,
+            stroke_color: self.style.stroke_color,
+            stroke_k,
+            stroke_to: to,
+            current: None,
+            span: None,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! The triangle primitive
+
+use crate::coord::Coord;
+use crate::drawable::{Dimensions, Pixel};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::polygon::{Polygon, PolygonIterator};
+use crate::primitives::Primitive;
+use crate::style::{Style, StrokeAlignment, WithStyle};
+
+/// A triangle connecting three points.
+///
+/// Implemented as a thin wrapper over [`Polygon`](../polygon/struct.Polygon.html) with `N = 3`, so
+/// it gets the same even-odd scanline fill for free. That also means it inherits `Polygon`'s
+/// stroke rendering: `stroke_width` and `stroke_alignment` are accepted but not yet honored, since
+/// edges are still rasterized as 1px Bresenham lines. See `Ellipse`'s concentric-ring stroke for
+/// the primitive that currently honors them.
+///
+/// ```rust
+/// use embedded_graphics::primitives::Triangle;
+/// use embedded_graphics::coord::Coord;
+///
+/// let triangle: Triangle<u8> =
+///     Triangle::new(Coord::new(10, 20), Coord::new(30, 40), Coord::new(50, 10));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Triangle<C: PixelColor> {
+    inner: Polygon<C, 3>,
+}
+
+impl<C> Triangle<C>
+where
+    C: PixelColor,
+{
+    /// Create a new triangle connecting `a`, `b`, and `c`
+    pub fn new(a: Coord, b: Coord, c: Coord) -> Self {
+        Triangle {
+            inner: Polygon::new([a, b, c]),
+        }
+    }
+}
+
+impl<C> Primitive for Triangle<C> where C: PixelColor {}
+
+impl<C> Dimensions for Triangle<C>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Coord {
+        self.inner.top_left()
+    }
+
+    fn bottom_right(&self) -> Coord {
+        self.inner.bottom_right()
+    }
+
+    fn size(&self) -> Coord {
+        self.inner.size()
+    }
+}
+
+impl<C> WithStyle<C> for Triangle<C>
+where
+    C: PixelColor,
+{
+    fn style(self, style: Style<C>) -> Self {
+        Triangle {
+            inner: self.inner.style(style),
+        }
+    }
+
+    fn stroke(self, color: Option<C>) -> Self {
+        Triangle {
+            inner: self.inner.stroke(color),
+        }
+    }
+
+    // Accepted for consistency with the other primitives; see the type-level doc comment for why
+    // this doesn't yet change the rendered outline.
+    fn stroke_width(self, width: u8) -> Self {
+        Triangle {
+            inner: self.inner.stroke_width(width),
+        }
+    }
+
+    fn stroke_alignment(self, alignment: StrokeAlignment) -> Self {
+        Triangle {
+            inner: self.inner.stroke_alignment(alignment),
+        }
+    }
+
+    fn fill(self, color: Option<C>) -> Self {
+        Triangle {
+            inner: self.inner.fill(color),
+        }
+    }
+}
+
+/// Pixel iterator for the `Triangle` primitive; delegates to
+/// [`PolygonIterator`](../polygon/struct.PolygonIterator.html).
+#[derive(Debug, Clone)]
+pub struct TriangleIterator<C: PixelColor>(PolygonIterator<C, 3>);
+
+impl<C> Iterator for TriangleIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<C> IntoIterator for Triangle<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = TriangleIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TriangleIterator(self.inner.into_iter())
+    }
+}
@@ -0,0 +1,81 @@
+//! Shared drawing style: stroke color/width/alignment and fill color.
+
+use crate::pixelcolor::PixelColor;
+
+/// Where a stroke is drawn relative to a shape's geometric outline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StrokeAlignment {
+    /// The stroke is drawn entirely inside the outline.
+    Inside,
+    /// The stroke straddles the outline, split as evenly as possible between inside and outside.
+    #[default]
+    Center,
+    /// The stroke is drawn entirely outside the outline.
+    Outside,
+}
+
+/// Visual style shared by every primitive: stroke color/width/alignment and fill color.
+#[derive(Debug, Clone, Copy)]
+pub struct Style<C: PixelColor> {
+    /// Stroke color. `None` draws no stroke.
+    pub stroke_color: Option<C>,
+    /// Stroke width, in pixels. Has no effect while `stroke_color` is `None`.
+    pub stroke_width: u8,
+    /// Where the stroke is drawn relative to the shape's outline. Has no effect while
+    /// `stroke_color` is `None`.
+    pub stroke_alignment: StrokeAlignment,
+    /// Fill color. `None` draws no fill.
+    pub fill_color: Option<C>,
+}
+
+impl<C> Default for Style<C>
+where
+    C: PixelColor,
+{
+    fn default() -> Self {
+        Style {
+            stroke_color: None,
+            stroke_width: 1,
+            stroke_alignment: StrokeAlignment::default(),
+            fill_color: None,
+        }
+    }
+}
+
+/// Fluent style-setting methods, implemented by every primitive.
+pub trait WithStyle<C: PixelColor> {
+    /// Replace the whole style in one call.
+    fn style(self, style: Style<C>) -> Self;
+
+    /// Set the stroke color. `None` draws no stroke. Shorthand for a color-only stroke; combine
+    /// with [`stroke_width`](#tymethod.stroke_width) for a thicker border.
+    fn stroke(self, color: Option<C>) -> Self;
+
+    /// Set the stroke width, in pixels. Defaults to `1`.
+    fn stroke_width(self, width: u8) -> Self;
+
+    /// Set where the stroke is drawn relative to the shape's outline. Defaults to
+    /// [`StrokeAlignment::Center`](enum.StrokeAlignment.html#variant.Center).
+    fn stroke_alignment(self, alignment: StrokeAlignment) -> Self;
+
+    /// Set the fill color. `None` draws no fill.
+    fn fill(self, color: Option<C>) -> Self;
+}
+
+/// Returns the inclusive `(from, to)` pixel offsets, relative to a shape's nominal outline, that
+/// a stroke of `width` and `alignment` occupies. `from` is always `<= 0` and `to` is always
+/// `>= 0` — e.g. `Inside` returns a range that ends at `0`, while `Outside` returns a range that
+/// starts at `0`.
+pub(crate) fn stroke_offsets(width: u8, alignment: StrokeAlignment) -> (i32, i32) {
+    let width = width.max(1) as i32;
+
+    match alignment {
+        StrokeAlignment::Center => {
+            let before = width / 2;
+            let after = width - 1 - before;
+            (-before, after)
+        }
+        StrokeAlignment::Inside => (-(width - 1), 0),
+        StrokeAlignment::Outside => (0, width - 1),
+    }
+}